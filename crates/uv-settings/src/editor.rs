@@ -0,0 +1,243 @@
+use std::path::PathBuf;
+
+use serde::de::Error as _;
+use uv_fs::Simplified;
+
+use crate::Error;
+
+/// The scope a writable configuration operation should target.
+///
+/// Mirrors the scopes that [`crate::FilesystemOptions`] can be loaded from, but for `config
+/// set`/`config edit` we also need to *choose* a canonical location when no file exists yet.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ConfigScope<'a> {
+    /// `--user`: the user-wide `uv.toml`.
+    User,
+    /// `--system`: the system-wide `uv.toml`.
+    System,
+    /// `--project` (the default): the nearest `uv.toml` or `pyproject.toml`, searched upwards
+    /// from `dir`.
+    Project { dir: &'a std::path::Path },
+}
+
+impl ConfigScope<'_> {
+    /// Resolve the canonical `uv.toml` path for this scope, without requiring that it exists.
+    fn default_path(self) -> Result<PathBuf, Error> {
+        match self {
+            Self::User => {
+                let dir = crate::user_config_dir().ok_or_else(|| {
+                    Error::NoConfigDirectory(
+                        "could not determine the user configuration directory".to_string(),
+                    )
+                })?;
+                Ok(dir.join("uv").join("uv.toml"))
+            }
+            Self::System => {
+                // Prefer the system file that uv actually reads, if one exists.
+                if let Some(path) = crate::system_config_file() {
+                    return Ok(path);
+                }
+                #[cfg(windows)]
+                {
+                    let system_drive = std::env::var("SYSTEMDRIVE").map_err(|_| {
+                        Error::NoConfigDirectory("%SYSTEMDRIVE% is not set".to_string())
+                    })?;
+                    Ok(PathBuf::from(system_drive)
+                        .join("ProgramData")
+                        .join("uv")
+                        .join("uv.toml"))
+                }
+                #[cfg(not(windows))]
+                {
+                    Ok(PathBuf::from("/etc/uv/uv.toml"))
+                }
+            }
+            Self::Project { dir } => {
+                for ancestor in dir.ancestors() {
+                    let uv_toml = ancestor.join("uv.toml");
+                    if uv_toml.is_file() {
+                        return Ok(uv_toml);
+                    }
+                    let pyproject_toml = ancestor.join("pyproject.toml");
+                    if pyproject_toml.is_file() {
+                        return Ok(pyproject_toml);
+                    }
+                }
+                // No existing project configuration; default to a `uv.toml` in `dir`.
+                Ok(dir.join("uv.toml"))
+            }
+        }
+    }
+
+    /// Locate the `uv.toml` (or `pyproject.toml`) for this scope, creating a new, empty `uv.toml`
+    /// and its parent directories if none exists.
+    pub fn locate_or_create(self) -> Result<PathBuf, Error> {
+        let path = self.default_path()?;
+        if path.is_file() {
+            return Ok(path);
+        }
+        if let Some(parent) = path.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        fs_err::write(&path, "")?;
+        Ok(path)
+    }
+}
+
+/// Set `key_path` (a dotted path, e.g. `pip.index-url`) to `value` in the `uv.toml` for `scope`,
+/// creating the file if necessary. Existing comments and formatting are preserved.
+///
+/// uv only ever reads project settings from a `[tool.uv]` table, so when `scope` resolves to a
+/// `pyproject.toml`, `key_path` is nested under `tool.uv` rather than written at the document
+/// root.
+pub fn set(scope: ConfigScope<'_>, key_path: &str, value: toml_edit::Value) -> Result<PathBuf, Error> {
+    let path = scope.locate_or_create()?;
+    let content = fs_err::read_to_string(&path)?;
+    let mut document = content.parse::<toml_edit::DocumentMut>().map_err(|err| {
+        Error::UvToml(path.user_display().to_string(), toml::de::Error::custom(err.to_string()))
+    })?;
+
+    let mut table = document.as_table_mut();
+    let mut prefix = String::new();
+    if is_pyproject_toml(&path) {
+        for segment in ["tool", "uv"] {
+            if !prefix.is_empty() {
+                prefix.push('.');
+            }
+            prefix.push_str(segment);
+            table = table
+                .entry(segment)
+                .or_insert_with(|| toml_edit::Item::Table(toml_edit::Table::new()))
+                .as_table_mut()
+                .ok_or_else(|| Error::NotATable(prefix.clone()))?;
+        }
+    }
+
+    let mut segments = key_path.split('.').peekable();
+    while let Some(segment) = segments.next() {
+        if !prefix.is_empty() {
+            prefix.push('.');
+        }
+        prefix.push_str(segment);
+
+        if segments.peek().is_none() {
+            table[segment] = toml_edit::value(value);
+            break;
+        }
+        table = table
+            .entry(segment)
+            .or_insert_with(|| toml_edit::Item::Table(toml_edit::Table::new()))
+            .as_table_mut()
+            .ok_or_else(|| Error::NotATable(prefix.clone()))?;
+    }
+
+    fs_err::write(&path, document.to_string())?;
+    Ok(path)
+}
+
+/// Return `true` if `path`'s file name is `pyproject.toml`.
+fn is_pyproject_toml(path: &std::path::Path) -> bool {
+    path.file_name().is_some_and(|name| name == "pyproject.toml")
+}
+
+/// Open `$EDITOR` (falling back to `vi` if unset) on the `uv.toml` for `scope`, creating it first
+/// if it does not yet exist.
+pub fn edit(scope: ConfigScope<'_>) -> Result<(), Error> {
+    let path = scope.locate_or_create()?;
+
+    let editor = std::env::var_os("EDITOR").unwrap_or_else(|| "vi".into());
+
+    let status = std::process::Command::new(editor)
+        .arg(&path)
+        .status()
+        .map_err(Error::Io)?;
+
+    if !status.success() {
+        return Err(Error::EditorFailed(path.user_display().to_string()));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use super::{set, ConfigScope};
+    use crate::Error;
+
+    #[test]
+    fn set_writes_at_root_for_uv_toml() {
+        let td = tempfile::tempdir().unwrap();
+        let path = set(
+            ConfigScope::Project { dir: td.path() },
+            "pip.offline",
+            toml_edit::Value::from(true),
+        )
+        .unwrap();
+
+        assert_eq!(path, td.path().join("uv.toml"));
+        let content = fs_err::read_to_string(&path).unwrap();
+        let value: toml::Value = toml::from_str(&content).unwrap();
+        assert_eq!(value["pip"]["offline"].as_bool(), Some(true));
+    }
+
+    #[test]
+    fn set_nests_under_tool_uv_for_pyproject_toml() {
+        let td = tempfile::tempdir().unwrap();
+        fs_err::write(td.path().join("pyproject.toml"), "[project]\nname = \"demo\"\n").unwrap();
+
+        let path = set(
+            ConfigScope::Project { dir: td.path() },
+            "pip.offline",
+            toml_edit::Value::from(true),
+        )
+        .unwrap();
+
+        assert_eq!(path, td.path().join("pyproject.toml"));
+        let content = fs_err::read_to_string(&path).unwrap();
+        let value: toml::Value = toml::from_str(&content).unwrap();
+        assert_eq!(value["project"]["name"].as_str(), Some("demo"));
+        assert_eq!(value["tool"]["uv"]["pip"]["offline"].as_bool(), Some(true));
+    }
+
+    #[test]
+    fn set_rejects_indexing_into_a_non_table() {
+        let td = tempfile::tempdir().unwrap();
+        fs_err::write(td.path().join("uv.toml"), "pip = \"not-a-table\"\n").unwrap();
+
+        let err = set(
+            ConfigScope::Project { dir: td.path() },
+            "pip.offline",
+            toml_edit::Value::from(true),
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, Error::NotATable(key) if key == "pip"));
+    }
+
+    #[test]
+    fn edit_prefers_editor_over_visual() {
+        let visual_var = env::var_os("VISUAL");
+        let editor_var = env::var_os("EDITOR");
+
+        // `false` always exits non-zero; if `edit` used `$VISUAL` this would fail.
+        env::set_var("VISUAL", "false");
+        // `true` always exits zero; `edit` must prefer `$EDITOR`.
+        env::set_var("EDITOR", "true");
+
+        let td = tempfile::tempdir().unwrap();
+        let result = super::edit(ConfigScope::Project { dir: td.path() });
+
+        match visual_var {
+            Some(value) => env::set_var("VISUAL", value),
+            None => env::remove_var("VISUAL"),
+        }
+        match editor_var {
+            Some(value) => env::set_var("EDITOR", value),
+            None => env::remove_var("EDITOR"),
+        }
+
+        result.unwrap();
+    }
+}
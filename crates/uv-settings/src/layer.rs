@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::Combine;
+use crate::Options;
+
+/// The origin of a [`ConfigLayer`], ordered from lowest to highest precedence.
+///
+/// This mirrors the order in which `uv` resolves configuration: a system-wide file is
+/// overridden by a user-wide file, which is overridden by the workspace configuration, which is
+/// overridden by an explicit `--config-file`, which is in turn overridden by the environment and
+/// command-line flags.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ConfigSource {
+    /// A system-wide configuration file (e.g., `/etc/uv/uv.toml`).
+    System(PathBuf),
+    /// A user-wide configuration file (e.g., `~/.config/uv/uv.toml`).
+    User(PathBuf),
+    /// A workspace `uv.toml` or `[tool.uv]` table in a `pyproject.toml`.
+    Workspace(PathBuf),
+    /// An explicit `--config-file`.
+    ConfigFile(PathBuf),
+    /// Settings derived from environment variables or command-line flags.
+    Environment,
+}
+
+impl ConfigSource {
+    /// Return the path that this [`ConfigSource`] was loaded from, if any.
+    pub fn path(&self) -> Option<&std::path::Path> {
+        match self {
+            Self::System(path) | Self::User(path) | Self::Workspace(path) | Self::ConfigFile(path) => {
+                Some(path)
+            }
+            Self::Environment => None,
+        }
+    }
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::System(path) => write!(f, "{}", path.display()),
+            Self::User(path) => write!(f, "{}", path.display()),
+            Self::Workspace(path) => write!(f, "{}", path.display()),
+            Self::ConfigFile(path) => write!(f, "{}", path.display()),
+            Self::Environment => write!(f, "<environment>"),
+        }
+    }
+}
+
+/// A single [`Options`] value paired with the [`ConfigSource`] it was loaded from.
+#[derive(Debug, Clone)]
+pub struct ConfigLayer {
+    pub(crate) source: ConfigSource,
+    pub(crate) options: Options,
+}
+
+impl ConfigLayer {
+    /// Create a new [`ConfigLayer`] from a parsed [`Options`] and the [`ConfigSource`] it came
+    /// from.
+    pub fn new(source: ConfigSource, options: Options) -> Self {
+        Self { source, options }
+    }
+
+    /// The source this layer was loaded from.
+    pub fn source(&self) -> &ConfigSource {
+        &self.source
+    }
+
+    /// The parsed options for this layer.
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+}
+
+/// A map from a dotted configuration key (e.g. `pip.index-url`) to the [`ConfigSource`] that
+/// provided its effective value.
+#[derive(Debug, Clone, Default)]
+pub struct Provenance(BTreeMap<String, ConfigSource>);
+
+impl Provenance {
+    /// Return the [`ConfigSource`] that provided the effective value for `key`, if known.
+    pub fn get(&self, key: &str) -> Option<&ConfigSource> {
+        self.0.get(key)
+    }
+
+    /// Iterate over all recorded `(key, source)` pairs, in key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &ConfigSource)> {
+        self.0.iter()
+    }
+}
+
+/// The result of merging an ordered stack of [`ConfigLayer`]s (lowest to highest precedence)
+/// while retaining, for every leaf setting, which layer's value won.
+#[derive(Debug, Clone)]
+pub struct LayeredOptions {
+    options: Options,
+    provenance: Provenance,
+}
+
+impl LayeredOptions {
+    /// Merge `layers`, applying each in turn so that later layers take precedence, and record
+    /// which layer contributed each effective leaf value.
+    ///
+    /// Provenance is tracked independently of the merge itself: for every leaf a layer defines
+    /// (regardless of whether that value differs from a lower layer's), the higher-precedence
+    /// layer's attribution overwrites any earlier one, so the last layer to define a key is
+    /// always the one credited — even if it happens to repeat the same value.
+    pub fn merge(layers: Vec<ConfigLayer>) -> Self {
+        let mut merged = Options::default();
+        let mut provenance = Provenance::default();
+
+        for layer in layers {
+            mark_all(String::new(), &to_value(&layer.options), &layer.source, &mut provenance.0);
+            merged = layer.options.clone().combine(merged);
+        }
+
+        Self { options: merged, provenance }
+    }
+
+    /// Consume `self`, returning the merged [`Options`].
+    pub fn into_options(self) -> Options {
+        self.options
+    }
+
+    /// The merged [`Options`].
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    /// The per-key provenance of the merged result.
+    pub fn provenance(&self) -> &Provenance {
+        &self.provenance
+    }
+
+    /// Render each effective key alongside its resolved value and the file (or environment) that
+    /// provided it, akin to `hg config --source` or `uv config show --sources`.
+    pub fn fmt_sources(&self) -> String {
+        let merged_value = to_value(&self.options);
+        let mut out = String::new();
+        for (key, source) in self.provenance.iter() {
+            let value = get_by_path(&merged_value, key)
+                .map(ToString::to_string)
+                .unwrap_or_else(|| "<unknown>".to_string());
+            out.push_str(&format!("{key} = {value} # {source}\n"));
+        }
+        out
+    }
+}
+
+/// Serialize `options` to a [`toml::Value`] for structural diffing, falling back to an empty
+/// table if serialization fails (which should not happen for well-formed [`Options`]).
+fn to_value<T: serde::Serialize>(options: &T) -> toml::Value {
+    toml::Value::try_from(options).unwrap_or_else(|_| toml::Value::Table(Default::default()))
+}
+
+/// Look up a dotted key path (e.g. `pip.index-url`) within a [`toml::Value`] table.
+fn get_by_path<'a>(value: &'a toml::Value, path: &str) -> Option<&'a toml::Value> {
+    let mut current = value;
+    for segment in path.split('.') {
+        current = current.as_table()?.get(segment)?;
+    }
+    Some(current)
+}
+
+/// Mark every leaf beneath `value` as having come from `source`, overwriting any existing
+/// attribution for the same key — used so that, across an ordered sequence of layers, the last
+/// (highest-precedence) layer to define a key is always the one credited.
+fn mark_all(prefix: String, value: &toml::Value, source: &ConfigSource, out: &mut BTreeMap<String, ConfigSource>) {
+    match value {
+        toml::Value::Table(table) => {
+            for (key, value) in table {
+                let key_path = if prefix.is_empty() { key.clone() } else { format!("{prefix}.{key}") };
+                mark_all(key_path, value, source, out);
+            }
+        }
+        _ => {
+            out.insert(prefix, source.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    use super::{get_by_path, mark_all, ConfigSource};
+
+    fn source(name: &str) -> ConfigSource {
+        ConfigSource::Workspace(PathBuf::from(name))
+    }
+
+    fn table(entries: &[(&str, toml::Value)]) -> toml::Value {
+        let mut table = toml::map::Map::new();
+        for (key, value) in entries {
+            table.insert((*key).to_string(), value.clone());
+        }
+        toml::Value::Table(table)
+    }
+
+    #[test]
+    fn mark_all_attributes_every_leaf() {
+        let value = table(&[
+            ("index-url", toml::Value::String("https://example.com".to_string())),
+            (
+                "pip",
+                table(&[("offline", toml::Value::Boolean(true))]),
+            ),
+        ]);
+
+        let mut out = BTreeMap::new();
+        mark_all(String::new(), &value, &source("a"), &mut out);
+
+        assert_eq!(out.get("index-url"), Some(&source("a")));
+        assert_eq!(out.get("pip.offline"), Some(&source("a")));
+    }
+
+    #[test]
+    fn mark_all_overwrites_even_on_identical_value() {
+        // A higher-precedence layer that repeats the same value must still be credited, not the
+        // lower-precedence layer that introduced it.
+        let value = table(&[("index-url", toml::Value::String("https://example.com".to_string()))]);
+
+        let mut out = BTreeMap::new();
+        mark_all(String::new(), &value, &source("lower"), &mut out);
+        mark_all(String::new(), &value, &source("higher"), &mut out);
+
+        assert_eq!(out.get("index-url"), Some(&source("higher")));
+    }
+
+    #[test]
+    fn get_by_path_resolves_nested_keys() {
+        let value = table(&[(
+            "pip",
+            table(&[("index-url", toml::Value::String("https://example.com".to_string()))]),
+        )]);
+
+        assert_eq!(
+            get_by_path(&value, "pip.index-url"),
+            Some(&toml::Value::String("https://example.com".to_string()))
+        );
+        assert_eq!(get_by_path(&value, "pip.missing"), None);
+        assert_eq!(get_by_path(&value, "missing"), None);
+    }
+}
@@ -8,10 +8,19 @@ use uv_static::EnvVars;
 use uv_warnings::warn_user;
 
 pub use crate::combine::*;
+pub use crate::editor::*;
+pub use crate::layer::*;
 pub use crate::settings::*;
 
 mod combine;
+mod editor;
+mod layer;
 mod settings;
+#[cfg(not(windows))]
+mod xdg;
+
+#[cfg(not(windows))]
+use crate::xdg::BaseDirectories;
 
 /// The [`Options`] as loaded from a configuration file on disk.
 #[derive(Debug, Clone)]
@@ -34,12 +43,40 @@ impl Deref for FilesystemOptions {
 
 impl FilesystemOptions {
     /// Load the user [`FilesystemOptions`].
+    ///
+    /// On Linux and macOS, this searches the full XDG base-directory precedence: `$XDG_CONFIG_HOME`
+    /// (or `~/.config`) followed by each entry of `$XDG_CONFIG_DIRS`, merging every `uv/uv.toml`
+    /// found with earlier (higher-precedence) directories winning. On Windows, a single
+    /// `%APPDATA%\uv\uv.toml` is read.
+    #[cfg(not(windows))]
+    pub fn user() -> Result<Option<Self>, Error> {
+        let files = BaseDirectories::user().uv_toml_files();
+        if files.is_empty() {
+            debug!("No user configuration found in the XDG search path");
+            return Ok(None);
+        }
+
+        // `uv_toml_files` is ordered highest-precedence first; fold right-to-left so the
+        // highest-precedence file's values win.
+        let mut merged: Option<Options> = None;
+        for file in files.into_iter().rev() {
+            debug!("Found user configuration in: `{}`", file.display());
+            let options = read_file(&file)?;
+            merged = Some(match merged {
+                Some(acc) => options.combine(acc),
+                None => options,
+            });
+        }
+
+        Ok(merged.map(Self))
+    }
+
+    #[cfg(windows)]
     pub fn user() -> Result<Option<Self>, Error> {
         let Some(dir) = user_config_dir() else {
             return Ok(None);
         };
-        let root = dir.join("uv");
-        let file = root.join("uv.toml");
+        let file = dir.join("uv").join("uv.toml");
 
         debug!("Searching for user configuration in: `{}`", file.display());
         match read_file(&file) {
@@ -48,14 +85,6 @@ impl FilesystemOptions {
                 Ok(Some(Self(options)))
             }
             Err(Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
-            Err(_) if !dir.is_dir() => {
-                // Ex) `XDG_CONFIG_HOME=/dev/null`
-                debug!(
-                    "User configuration directory `{}` does not exist or is not a directory",
-                    dir.display()
-                );
-                Ok(None)
-            }
             Err(err) => Err(err),
         }
     }
@@ -103,68 +132,101 @@ impl FilesystemOptions {
     pub fn from_directory(dir: &Path) -> Result<Option<Self>, Error> {
         // Read a `uv.toml` file in the current directory.
         let path = dir.join("uv.toml");
-        match fs_err::read_to_string(&path) {
-            Ok(content) => {
-                let options: Options = toml::from_str(&content)
-                    .map_err(|err| Error::UvToml(path.user_display().to_string(), err))?;
-
-                // If the directory also contains a `[tool.uv]` table in a `pyproject.toml` file,
-                // warn.
-                let pyproject = dir.join("pyproject.toml");
-                if let Some(pyproject) = fs_err::read_to_string(pyproject)
-                    .ok()
-                    .and_then(|content| toml::from_str::<PyProjectToml>(&content).ok())
-                {
-                    if pyproject.tool.is_some_and(|tool| tool.uv.is_some()) {
-                        warn_user!(
-                            "Found both a `uv.toml` file and a `[tool.uv]` section in an adjacent `pyproject.toml`. The `[tool.uv]` section will be ignored in favor of the `uv.toml` file."
-                        );
-                    }
+        if path.is_file() {
+            let options = read_file(&path)?;
+
+            // If the directory also contains a `[tool.uv]` table in a `pyproject.toml` file,
+            // warn.
+            let pyproject = dir.join("pyproject.toml");
+            if let Some(pyproject) = fs_err::read_to_string(pyproject)
+                .ok()
+                .and_then(|content| toml::from_str::<PyProjectToml>(&content).ok())
+            {
+                if pyproject.tool.is_some_and(|tool| tool.uv.is_some()) {
+                    warn_user!(
+                        "Found both a `uv.toml` file and a `[tool.uv]` section in an adjacent `pyproject.toml`. The `[tool.uv]` section will be ignored in favor of the `uv.toml` file."
+                    );
                 }
-
-                debug!("Found workspace configuration at `{}`", path.display());
-                return Ok(Some(Self(options)));
             }
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
-            Err(err) => return Err(err.into()),
+
+            debug!("Found workspace configuration at `{}`", path.display());
+            return Ok(Some(Self(options)));
         }
 
         // Read a `pyproject.toml` file in the current directory.
         let path = dir.join("pyproject.toml");
-        match fs_err::read_to_string(&path) {
-            Ok(content) => {
-                // Parse, but skip any `pyproject.toml` that doesn't have a `[tool.uv]` section.
-                let pyproject: PyProjectToml = toml::from_str(&content)
-                    .map_err(|err| Error::PyprojectToml(path.user_display().to_string(), err))?;
-                let Some(tool) = pyproject.tool else {
-                    debug!(
-                        "Skipping `pyproject.toml` in `{}` (no `[tool]` section)",
-                        dir.display()
-                    );
-                    return Ok(None);
-                };
-                let Some(options) = tool.uv else {
-                    debug!(
-                        "Skipping `pyproject.toml` in `{}` (no `[tool.uv]` section)",
-                        dir.display()
-                    );
-                    return Ok(None);
-                };
-
-                debug!("Found workspace configuration at `{}`", path.display());
-                return Ok(Some(Self(options)));
-            }
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
-            Err(err) => return Err(err.into()),
-        }
+        let mut visited = Vec::new();
+        let Some(options) = read_pyproject_tool_uv(&path, &mut visited, 0)? else {
+            debug!(
+                "Skipping `pyproject.toml` in `{}` (no `[tool.uv]` section)",
+                dir.display()
+            );
+            return Ok(None);
+        };
 
-        Ok(None)
+        debug!("Found workspace configuration at `{}`", path.display());
+        Ok(Some(Self(options)))
     }
 
     /// Load a [`FilesystemOptions`] from a `uv.toml` file.
     pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
         Ok(Self(read_file(path.as_ref())?))
     }
+
+    /// Load every user [`ConfigLayer`] found along the XDG search path, ordered from lowest to
+    /// highest precedence (matching the order [`LayeredOptions::merge`] expects).
+    #[cfg(not(windows))]
+    pub fn user_layers() -> Result<Vec<ConfigLayer>, Error> {
+        let mut layers = Vec::new();
+        for file in BaseDirectories::user().uv_toml_files().into_iter().rev() {
+            let options = read_file(&file)?;
+            layers.push(ConfigLayer::new(ConfigSource::User(file), options));
+        }
+        Ok(layers)
+    }
+
+    /// Load the user [`ConfigLayer`], tagged with the [`ConfigSource`] it was read from.
+    #[cfg(windows)]
+    pub fn user_layers() -> Result<Vec<ConfigLayer>, Error> {
+        let Some(dir) = user_config_dir() else {
+            return Ok(Vec::new());
+        };
+        let file = dir.join("uv").join("uv.toml");
+        Ok(Self::user()?
+            .map(|options| ConfigLayer::new(ConfigSource::User(file), options.into_options()))
+            .into_iter()
+            .collect())
+    }
+
+    /// Load the system [`ConfigLayer`], tagged with the [`ConfigSource`] it was read from.
+    pub fn system_layer() -> Result<Option<ConfigLayer>, Error> {
+        let Some(file) = system_config_file() else {
+            return Ok(None);
+        };
+        Ok(Self::system()?.map(|options| ConfigLayer::new(ConfigSource::System(file), options.into_options())))
+    }
+
+    /// Find the workspace [`ConfigLayer`] for the given path, tagged with the [`ConfigSource`] it
+    /// was read from.
+    pub fn find_layer(path: &Path) -> Result<Option<ConfigLayer>, Error> {
+        for ancestor in path.ancestors() {
+            let uv_toml = ancestor.join("uv.toml");
+            let pyproject_toml = ancestor.join("pyproject.toml");
+            match Self::from_directory(ancestor) {
+                Ok(Some(options)) => {
+                    let source = if uv_toml.is_file() {
+                        ConfigSource::Workspace(uv_toml)
+                    } else {
+                        ConfigSource::Workspace(pyproject_toml)
+                    };
+                    return Ok(Some(ConfigLayer::new(source, options.into_options())));
+                }
+                Ok(None) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(None)
+    }
 }
 
 impl From<Options> for FilesystemOptions {
@@ -197,18 +259,10 @@ fn user_config_dir() -> Option<PathBuf> {
 #[cfg(not(windows))]
 fn locate_system_config_xdg(value: Option<&str>) -> Option<PathBuf> {
     // On Linux/MacOS systems, read the XDG_CONFIG_DIRS environment variable
-
-    let default = "/etc/xdg";
-    let config_dirs = value.filter(|s| !s.is_empty()).unwrap_or(default);
-
-    for dir in config_dirs.split(':').take_while(|s| !s.is_empty()) {
-        let uv_toml_path = Path::new(dir).join("uv").join("uv.toml");
-
-        if uv_toml_path.is_file() {
-            return Some(uv_toml_path);
-        }
-    }
-    None
+    BaseDirectories::split(value)
+        .into_iter()
+        .map(|dir| dir.join("uv").join("uv.toml"))
+        .find(|path| path.is_file())
 }
 
 /// Returns the path to the system configuration file.
@@ -229,9 +283,7 @@ fn system_config_file() -> Option<PathBuf> {
 
     #[cfg(not(windows))]
     {
-        if let Some(path) =
-            locate_system_config_xdg(std::env::var(EnvVars::XDG_CONFIG_DIRS).ok().as_deref())
-        {
+        if let Some(path) = BaseDirectories::system().uv_toml_files().into_iter().next() {
             return Some(path);
         }
         // Fallback /etc/uv/uv.toml if XDG_CONFIG_DIRS is not set or no valid
@@ -241,12 +293,156 @@ fn system_config_file() -> Option<PathBuf> {
     }
 }
 
-/// Load [`Options`] from a `uv.toml` file.
+/// The maximum depth of `extends` chains that will be followed before giving up.
+///
+/// This guards against pathological (if not necessarily cyclic) chains of imports; true cycles
+/// are rejected outright, see [`read_file_with_imports`].
+const IMPORT_RECURSION_LIMIT: usize = 5;
+
+/// Load [`Options`] from a `uv.toml` file, following any `extends` directives it contains.
 fn read_file(path: &Path) -> Result<Options, Error> {
+    let mut visited = Vec::new();
+    read_file_with_imports(path, &mut visited, 0)
+}
+
+/// Load [`Options`] from `path`, recursively merging in any files named by an `extends` key.
+///
+/// Imported options are merged *underneath* the importing file's options, so that local values
+/// always win. `visited` tracks the current import chain (not the whole tree) so that diamond
+/// imports are allowed, but cycles are rejected.
+fn read_file_with_imports(path: &Path, visited: &mut Vec<PathBuf>, depth: usize) -> Result<Options, Error> {
+    if depth > IMPORT_RECURSION_LIMIT {
+        return Err(Error::ImportRecursionLimit(path.user_display().to_string()));
+    }
+
+    let canonical = fs_err::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    if visited.contains(&canonical) {
+        return Err(Error::ImportCycle(path.user_display().to_string()));
+    }
+
     let content = fs_err::read_to_string(path)?;
-    let options: Options = toml::from_str(&content)
+    let mut value: toml::Value = toml::from_str(&content)
         .map_err(|err| Error::UvToml(path.user_display().to_string(), err))?;
-    Ok(options)
+    let extends = take_extends(&mut value, path)?;
+
+    let options: Options = value
+        .try_into()
+        .map_err(|err| Error::UvToml(path.user_display().to_string(), err))?;
+
+    if extends.is_empty() {
+        return Ok(options);
+    }
+
+    visited.push(canonical);
+    let mut merged = options;
+    for import in extends {
+        let imported = read_file_with_imports(&import, visited, depth + 1)?;
+        merged = merged.combine(imported);
+    }
+    visited.pop();
+
+    Ok(merged)
+}
+
+/// Remove and resolve the `extends` key from a parsed `uv.toml` [`toml::Value`], if present.
+///
+/// `extends` may be a single string or an array of strings, each resolved relative to the
+/// directory containing `path`.
+fn take_extends(value: &mut toml::Value, path: &Path) -> Result<Vec<PathBuf>, Error> {
+    let Some(table) = value.as_table_mut() else {
+        return Ok(Vec::new());
+    };
+    let Some(extends) = table.remove("extends") else {
+        return Ok(Vec::new());
+    };
+    resolve_extends(extends, path)
+}
+
+/// Remove and resolve the `extends` key from the `[tool.uv]` table of a parsed `pyproject.toml`
+/// [`toml::Value`], if present.
+fn take_tool_uv_extends(value: &mut toml::Value, path: &Path) -> Result<Vec<PathBuf>, Error> {
+    let Some(uv) = value
+        .get_mut("tool")
+        .and_then(toml::Value::as_table_mut)
+        .and_then(|tool| tool.get_mut("uv"))
+        .and_then(toml::Value::as_table_mut)
+    else {
+        return Ok(Vec::new());
+    };
+    let Some(extends) = uv.remove("extends") else {
+        return Ok(Vec::new());
+    };
+    resolve_extends(extends, path)
+}
+
+/// Resolve an `extends` value (a string or array of strings) into absolute paths, relative to
+/// the directory containing `path`.
+fn resolve_extends(extends: toml::Value, path: &Path) -> Result<Vec<PathBuf>, Error> {
+    let base = path.parent().unwrap_or_else(|| Path::new("."));
+    let raw = match extends {
+        toml::Value::String(s) => vec![s],
+        toml::Value::Array(array) => array
+            .into_iter()
+            .map(|entry| {
+                entry
+                    .as_str()
+                    .map(ToString::to_string)
+                    .ok_or_else(|| Error::InvalidExtends(path.user_display().to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => return Err(Error::InvalidExtends(path.user_display().to_string())),
+    };
+
+    Ok(raw.into_iter().map(|s| base.join(s)).collect())
+}
+
+/// Load the `[tool.uv]` table from a `pyproject.toml`, following any `extends` directives it
+/// contains, the same way [`read_file_with_imports`] does for `uv.toml`.
+///
+/// Returns `Ok(None)` if the file does not exist or has no `[tool.uv]` section.
+fn read_pyproject_tool_uv(path: &Path, visited: &mut Vec<PathBuf>, depth: usize) -> Result<Option<Options>, Error> {
+    if depth > IMPORT_RECURSION_LIMIT {
+        return Err(Error::ImportRecursionLimit(path.user_display().to_string()));
+    }
+
+    let canonical = fs_err::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    if visited.contains(&canonical) {
+        return Err(Error::ImportCycle(path.user_display().to_string()));
+    }
+
+    let content = match fs_err::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut value: toml::Value = toml::from_str(&content)
+        .map_err(|err| Error::PyprojectToml(path.user_display().to_string(), err))?;
+    let extends = take_tool_uv_extends(&mut value, path)?;
+
+    let pyproject: PyProjectToml = value
+        .try_into()
+        .map_err(|err| Error::PyprojectToml(path.user_display().to_string(), err))?;
+    let Some(tool) = pyproject.tool else {
+        return Ok(None);
+    };
+    let Some(options) = tool.uv else {
+        return Ok(None);
+    };
+
+    if extends.is_empty() {
+        return Ok(Some(options));
+    }
+
+    visited.push(canonical);
+    let mut merged = options;
+    for import in extends {
+        let imported = read_file_with_imports(&import, visited, depth + 1)?;
+        merged = merged.combine(imported);
+    }
+    visited.pop();
+
+    Ok(Some(merged))
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -259,6 +455,24 @@ pub enum Error {
 
     #[error("Failed to parse: `{0}`")]
     UvToml(String, #[source] toml::de::Error),
+
+    #[error("`extends` must be a string or array of strings in: `{0}`")]
+    InvalidExtends(String),
+
+    #[error("Exceeded maximum `extends` depth of {IMPORT_RECURSION_LIMIT} while resolving: `{0}`")]
+    ImportRecursionLimit(String),
+
+    #[error("Detected cycle in `extends` chain at: `{0}`")]
+    ImportCycle(String),
+
+    #[error("Unable to locate a configuration file: {0}")]
+    NoConfigDirectory(String),
+
+    #[error("Editor exited with a non-zero status while editing: `{0}`")]
+    EditorFailed(String),
+
+    #[error("Cannot index into a non-table at `{0}`")]
+    NotATable(String),
 }
 
 #[cfg(test)]
@@ -334,4 +548,67 @@ mod test {
 
         env::set_var(EnvVars::SYSTEMDRIVE, sd);
     }
+
+    #[test]
+    fn extends_resolves_a_single_string() {
+        let td = tempfile::tempdir().unwrap();
+        fs_err::write(td.path().join("base.toml"), "").unwrap();
+        fs_err::write(td.path().join("uv.toml"), "extends = \"base.toml\"\n").unwrap();
+
+        crate::read_file(&td.path().join("uv.toml")).unwrap();
+    }
+
+    #[test]
+    fn extends_resolves_an_array_of_strings() {
+        let td = tempfile::tempdir().unwrap();
+        fs_err::write(td.path().join("a.toml"), "").unwrap();
+        fs_err::write(td.path().join("b.toml"), "").unwrap();
+        fs_err::write(
+            td.path().join("uv.toml"),
+            "extends = [\"a.toml\", \"b.toml\"]\n",
+        )
+        .unwrap();
+
+        crate::read_file(&td.path().join("uv.toml")).unwrap();
+    }
+
+    #[test]
+    fn extends_is_resolved_relative_to_the_importing_file() {
+        let td = tempfile::tempdir().unwrap();
+        fs_err::create_dir_all(td.path().join("nested")).unwrap();
+        fs_err::write(td.path().join("base.toml"), "").unwrap();
+        fs_err::write(
+            td.path().join("nested").join("uv.toml"),
+            "extends = \"../base.toml\"\n",
+        )
+        .unwrap();
+
+        crate::read_file(&td.path().join("nested").join("uv.toml")).unwrap();
+    }
+
+    #[test]
+    fn extends_rejects_a_cycle() {
+        let td = tempfile::tempdir().unwrap();
+        fs_err::write(td.path().join("a.toml"), "extends = \"b.toml\"\n").unwrap();
+        fs_err::write(td.path().join("b.toml"), "extends = \"a.toml\"\n").unwrap();
+
+        let err = crate::read_file(&td.path().join("a.toml")).unwrap_err();
+        assert!(matches!(err, crate::Error::ImportCycle(_)));
+    }
+
+    #[test]
+    fn extends_rejects_exceeding_the_recursion_limit() {
+        let td = tempfile::tempdir().unwrap();
+        for i in 0..=crate::IMPORT_RECURSION_LIMIT + 1 {
+            let next = if i <= crate::IMPORT_RECURSION_LIMIT {
+                format!("extends = \"f{}.toml\"\n", i + 1)
+            } else {
+                String::new()
+            };
+            fs_err::write(td.path().join(format!("f{i}.toml")), next).unwrap();
+        }
+
+        let err = crate::read_file(&td.path().join("f0.toml")).unwrap_err();
+        assert!(matches!(err, crate::Error::ImportRecursionLimit(_)));
+    }
 }
@@ -0,0 +1,117 @@
+use std::path::PathBuf;
+
+use uv_static::EnvVars;
+
+/// The default value of `$XDG_CONFIG_DIRS` when the environment variable is unset or empty.
+const DEFAULT_CONFIG_DIRS: &str = "/etc/xdg";
+
+/// A reusable helper for walking an XDG-style base-directory search path, used by both the user
+/// and system configuration scopes so they share the same precedence rules.
+///
+/// For the user scope this is `$XDG_CONFIG_HOME` (or `~/.config`) followed by each entry of
+/// `$XDG_CONFIG_DIRS`; for the system scope it is just `$XDG_CONFIG_DIRS`. In both cases, earlier
+/// directories take precedence over later ones.
+#[cfg(not(windows))]
+pub(crate) struct BaseDirectories {
+    dirs: Vec<PathBuf>,
+}
+
+#[cfg(not(windows))]
+impl BaseDirectories {
+    /// The search path for user configuration: `$XDG_CONFIG_HOME` (or `~/.config`), followed by
+    /// each entry of `$XDG_CONFIG_DIRS` (or `/etc/xdg`).
+    pub(crate) fn user() -> Self {
+        let mut dirs = Vec::new();
+        if let Some(home) = std::env::var_os(EnvVars::XDG_CONFIG_HOME)
+            .and_then(dirs_sys::is_absolute_path)
+            .or_else(|| dirs_sys::home_dir().map(|path| path.join(".config")))
+        {
+            dirs.push(home);
+        }
+        dirs.extend(Self::split(std::env::var(EnvVars::XDG_CONFIG_DIRS).ok().as_deref()));
+        Self { dirs }
+    }
+
+    /// The search path for system configuration: each entry of `$XDG_CONFIG_DIRS` (or
+    /// `/etc/xdg`).
+    pub(crate) fn system() -> Self {
+        Self {
+            dirs: Self::split(std::env::var(EnvVars::XDG_CONFIG_DIRS).ok().as_deref()),
+        }
+    }
+
+    /// Split a colon-separated `$XDG_CONFIG_DIRS`-style value into its component directories,
+    /// falling back to [`DEFAULT_CONFIG_DIRS`] when unset or empty.
+    pub(crate) fn split(value: Option<&str>) -> Vec<PathBuf> {
+        let value = value.filter(|s| !s.is_empty()).unwrap_or(DEFAULT_CONFIG_DIRS);
+        value
+            .split(':')
+            .take_while(|s| !s.is_empty())
+            .map(PathBuf::from)
+            .collect()
+    }
+
+    /// Return the `uv/uv.toml` path under each base directory that exists, in precedence order
+    /// (highest-precedence directory first).
+    pub(crate) fn uv_toml_files(&self) -> Vec<PathBuf> {
+        self.dirs
+            .iter()
+            .map(|dir| dir.join("uv").join("uv.toml"))
+            .filter(|path| path.is_file())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(windows))]
+mod test {
+    use std::env;
+
+    use uv_static::EnvVars;
+
+    use super::BaseDirectories;
+
+    #[test]
+    fn user_uv_toml_files_in_xdg_config_home_then_xdg_config_dirs_order() {
+        let home_var = env::var_os(EnvVars::XDG_CONFIG_HOME);
+        let dirs_var = env::var_os(EnvVars::XDG_CONFIG_DIRS);
+
+        let td = tempfile::tempdir().unwrap();
+        let home = td.path().join("home");
+        let other = td.path().join("other");
+        fs_err::create_dir_all(home.join("uv")).unwrap();
+        fs_err::create_dir_all(other.join("uv")).unwrap();
+        fs_err::write(home.join("uv").join("uv.toml"), "").unwrap();
+        fs_err::write(other.join("uv").join("uv.toml"), "").unwrap();
+
+        env::set_var(EnvVars::XDG_CONFIG_HOME, &home);
+        env::set_var(EnvVars::XDG_CONFIG_DIRS, &other);
+
+        let files = BaseDirectories::user().uv_toml_files();
+        assert_eq!(files, vec![home.join("uv").join("uv.toml"), other.join("uv").join("uv.toml")]);
+
+        match home_var {
+            Some(value) => env::set_var(EnvVars::XDG_CONFIG_HOME, value),
+            None => env::remove_var(EnvVars::XDG_CONFIG_HOME),
+        }
+        match dirs_var {
+            Some(value) => env::set_var(EnvVars::XDG_CONFIG_DIRS, value),
+            None => env::remove_var(EnvVars::XDG_CONFIG_DIRS),
+        }
+    }
+
+    #[test]
+    fn missing_directories_are_skipped() {
+        let dirs_var = env::var_os(EnvVars::XDG_CONFIG_DIRS);
+
+        let td = tempfile::tempdir().unwrap();
+        env::set_var(EnvVars::XDG_CONFIG_DIRS, td.path().join("does-not-exist"));
+
+        assert!(BaseDirectories::system().uv_toml_files().is_empty());
+
+        match dirs_var {
+            Some(value) => env::set_var(EnvVars::XDG_CONFIG_DIRS, value),
+            None => env::remove_var(EnvVars::XDG_CONFIG_DIRS),
+        }
+    }
+}